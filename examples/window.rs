@@ -1,7 +1,7 @@
 use log::{info, trace};
 use std::sync::Arc;
-use swamp_window::AppHandler;
-use winit::dpi::{PhysicalPosition, PhysicalSize};
+use swamp_window::{AppHandler, CursorIcon};
+use winit::dpi::{LogicalPosition, PhysicalPosition, PhysicalSize};
 use winit::event::{
     ElementState, InnerSizeWriter, MouseButton, MouseScrollDelta, Touch, TouchPhase,
 };
@@ -14,6 +14,8 @@ pub struct Handler {
 }
 
 impl AppHandler for Handler {
+    type UserEvent = ();
+
     // Query
     fn min_size(&self) -> (u16, u16) {
         (640, 480)
@@ -27,12 +29,24 @@ impl AppHandler for Handler {
         self.cursor_visible
     }
 
+    fn cursor_icon(&self) -> CursorIcon {
+        CursorIcon::Default
+    }
+
     // Window
-    fn redraw(&mut self) -> bool {
-        trace!("redraw");
+    fn redraw(&mut self, delta_seconds: f32, elapsed_seconds: f64) -> bool {
+        trace!("redraw delta:{delta_seconds} elapsed:{elapsed_seconds}");
         !self.should_quit
     }
 
+    fn suspended(&mut self) {
+        info!("suspended");
+    }
+
+    fn resumed(&mut self) {
+        info!("resumed");
+    }
+
     fn got_focus(&mut self) {
         info!("got focus");
     }
@@ -75,6 +89,10 @@ impl AppHandler for Handler {
         info!("cursor moved {physical_position:?}");
     }
 
+    fn cursor_moved_logical(&mut self, logical_position: LogicalPosition<f64>) {
+        info!("cursor moved logical {logical_position:?}");
+    }
+
     // Mouse
     fn mouse_input(&mut self, element_state: ElementState, button: MouseButton) {
         info!("mouse_input {element_state:?} {button:?}");
@@ -93,6 +111,10 @@ impl AppHandler for Handler {
         info!("touch {touch:?}");
     }
 
+    fn touch_logical(&mut self, logical_position: LogicalPosition<f64>) {
+        info!("touch logical {logical_position:?}");
+    }
+
     // Environment
     fn scale_factor_changed(&mut self, scale_factor: f64, mut inner_size_writer: InnerSizeWriter) {
         info!("scale factor changed {scale_factor:?}");
@@ -5,25 +5,33 @@
 
 use crate::dpi::PhysicalSize;
 use log::info;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 use winit::application::ApplicationHandler;
 use winit::dpi;
-use winit::dpi::PhysicalPosition;
+use winit::dpi::{LogicalPosition, PhysicalPosition};
 use winit::error::EventLoopError;
 use winit::event::{
     DeviceEvent, DeviceId, ElementState, InnerSizeWriter, MouseButton, MouseScrollDelta, Touch,
     TouchPhase, WindowEvent,
 };
-use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
+use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop, EventLoopProxy};
 use winit::keyboard::PhysicalKey;
 use winit::window::{Window, WindowAttributes, WindowId};
 
+pub use winit::window::CursorIcon;
+
 /// `AppHandler` - Handle window, cursor, mouse and keyboard events, designed for games and graphical applications.
 ///
 /// Think of `AppHandler` as your app’s backstage crew, handling everything
 /// from window setup to keyboard and mouse inputs, and making sure each frame
 /// redraws smoothly.
 pub trait AppHandler {
+    /// The user-defined event type that can be injected into the event loop from any
+    /// thread via an [`EventLoopProxy`]. Use `()` when no user events are needed.
+    type UserEvent: 'static;
+
     // Query functions
 
     /// Returns the minimum window size (width, height) in pixels that the application requires.
@@ -39,14 +47,42 @@ pub trait AppHandler {
 
     fn cursor_should_be_visible(&self) -> bool;
 
+    /// Returns the cursor icon the application currently wants the window to display.
+    ///
+    /// This lets handlers map hover/drag states to distinct system cursors
+    /// (text, resize, grab, crosshair, wait, ...). The icon is only applied when it
+    /// changes from the previously requested one.
+    fn cursor_icon(&self) -> CursorIcon;
+
     // Window Events
 
     /// Called to trigger a redraw of the application’s content.
     ///
     /// This method is generally called when the window needs to refresh its
     /// contents, such as after a resize or focus change.
+    ///
+    /// # Parameters
+    /// - `delta_seconds`: Seconds elapsed since the previous frame was drawn. It is `0.0`
+    ///   for the very first frame and for the first frame after the window is recreated.
+    /// - `elapsed_seconds`: Seconds elapsed since the first frame was drawn.
+    ///
     /// Return false if application should close
-    fn redraw(&mut self) -> bool;
+    fn redraw(&mut self, delta_seconds: f32, elapsed_seconds: f64) -> bool;
+
+    /// Called when the application has been suspended by the operating system.
+    ///
+    /// On mobile platforms (notably Android) the OS destroys the GPU surface when the
+    /// process is backgrounded, which invalidates any `Arc<Window>` handed out earlier.
+    /// Use this to release swapchain resources before they become invalid.
+    fn suspended(&mut self);
+
+    /// Called when the application is resumed, including once at startup after the
+    /// window has first been created.
+    ///
+    /// After a suspension the window and its surface are recreated before this is called, so
+    /// [`AppHandler::window_created`] fires again with a fresh window. Use this to
+    /// rebuild any resources released in [`AppHandler::suspended`].
+    fn resumed(&mut self);
 
     /// Called when the application window gains focus.
     ///
@@ -69,6 +105,32 @@ pub trait AppHandler {
     /// - `window`: A reference-counted pointer to the application window.
     fn window_created(&mut self, window: Arc<Window>);
 
+    /// Called when the user or OS requests that the window be closed.
+    ///
+    /// Return `true` to let the window close (the default), or `false` to veto the
+    /// request and keep the application running — useful for prompting the user to
+    /// save before quitting.
+    fn close_requested(&mut self) -> bool {
+        true
+    }
+
+    /// Called once at startup with a proxy that can wake the event loop from any thread.
+    ///
+    /// Clone the proxy and move it into a background thread (asset loading, networking)
+    /// to push a [`AppHandler::UserEvent`] back into the loop, which is then delivered to
+    /// [`AppHandler::user_event`]. Default is a no-op.
+    fn proxy_ready(&mut self, proxy: EventLoopProxy<Self::UserEvent>) {
+        let _ = proxy;
+    }
+
+    /// Called when a user event sent through the [`EventLoopProxy`] is received.
+    ///
+    /// This runs on the main thread, so it is a safe place to trigger a redraw or mutate
+    /// application state in response to work finished on another thread. Default is a no-op.
+    fn user_event(&mut self, event: Self::UserEvent) {
+        let _ = event;
+    }
+
     /// Called whenever the window is resized, providing the new physical size.
     ///
     /// This method should handle adjustments to the application’s layout and content
@@ -87,6 +149,19 @@ pub trait AppHandler {
     /// - `physical_key`: The physical key that was pressed or released.
     fn keyboard_input(&mut self, element_state: ElementState, physical_key: PhysicalKey);
 
+    /// Processes the text produced by a key press, already resolved for keyboard layout
+    /// and modifiers. IME composition and commits are not delivered here.
+    ///
+    /// This is the correct source for text entry (chat boxes, consoles, name fields),
+    /// whereas [`AppHandler::keyboard_input`] is the raw physical key and should be used
+    /// for game controls. Default is a no-op.
+    ///
+    /// # Parameters
+    /// - `text`: The committed characters produced by the key event.
+    fn text_input(&mut self, text: &str) {
+        let _ = text;
+    }
+
     // Cursor (Pointer) Events
 
     /// Called when the cursor enters the window.
@@ -108,6 +183,16 @@ pub trait AppHandler {
     ///   screen coordinates.
     fn cursor_moved(&mut self, physical_position: PhysicalPosition<f64>);
 
+    /// Handles cursor movement, providing the position in logical (scale-independent)
+    /// coordinates.
+    ///
+    /// This is the same movement as [`AppHandler::cursor_moved`] but with the current
+    /// scale factor already divided out, which is what UI and layout code usually wants.
+    ///
+    /// # Parameters
+    /// - `logical_position`: The current position of the cursor in logical coordinates.
+    fn cursor_moved_logical(&mut self, logical_position: LogicalPosition<f64>);
+
     // Mouse Events
 
     /// Handles mouse button input events, such as presses and releases.
@@ -138,6 +223,39 @@ pub trait AppHandler {
     ///   touch-specific information.
     fn touch(&mut self, touch: Touch);
 
+    /// Handles touch input, providing the touch location in logical (scale-independent)
+    /// coordinates.
+    ///
+    /// This is the same touch as [`AppHandler::touch`] but with the current scale factor
+    /// already divided out of its location, which is what UI and layout code usually wants.
+    ///
+    /// # Parameters
+    /// - `logical_position`: The touch location in logical coordinates.
+    fn touch_logical(&mut self, logical_position: LogicalPosition<f64>);
+
+    // File Drag-and-Drop Events
+
+    /// Called when a file has been dropped onto the window.
+    ///
+    /// # Parameters
+    /// - `path`: The path of the dropped file.
+    fn file_dropped(&mut self, path: PathBuf) {
+        let _ = path;
+    }
+
+    /// Called while a file is being hovered over the window, before it is dropped.
+    ///
+    /// This may be called multiple times, once per file being dragged.
+    ///
+    /// # Parameters
+    /// - `path`: The path of the hovered file.
+    fn file_hovered(&mut self, path: PathBuf) {
+        let _ = path;
+    }
+
+    /// Called when a file drag-and-drop operation is cancelled while hovering the window.
+    fn file_hover_cancelled(&mut self) {}
+
     // Environment or Screen Events
 
     /// Handles changes to the display scale factor, usually due to monitor DPI changes.
@@ -152,17 +270,21 @@ pub trait AppHandler {
     fn scale_factor_changed(&mut self, scale_factor: f64, inner_size_writer: InnerSizeWriter);
 }
 
-pub struct App<'a> {
+pub struct App<'a, T: 'static> {
     window: Option<Arc<Window>>,
-    handler: &'a mut (dyn AppHandler),
+    handler: &'a mut (dyn AppHandler<UserEvent = T>),
     window_attributes: WindowAttributes,
     is_focused: bool,
     cursor_is_visible: bool,
+    first_frame_at: Option<Instant>,
+    previous_frame_at: Option<Instant>,
+    scale_factor: f64,
+    cursor_icon: CursorIcon,
 }
 
-impl<'a> App<'a> {
+impl<'a, T: 'static> App<'a, T> {
     pub fn new(
-        handler: &'a mut dyn AppHandler,
+        handler: &'a mut dyn AppHandler<UserEvent = T>,
         title: &str,
         min_size: (u16, u16),
         start_size: (u16, u16),
@@ -182,11 +304,15 @@ impl<'a> App<'a> {
             window: None,
             is_focused: false,
             cursor_is_visible: true,
+            first_frame_at: None,
+            previous_frame_at: None,
+            scale_factor: 1.0,
+            cursor_icon: CursorIcon::Default,
         }
     }
 }
 
-impl ApplicationHandler for App<'_> {
+impl<T: 'static> ApplicationHandler<T> for App<'_, T> {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         if self.window.is_none() {
             info!("creating new window");
@@ -197,10 +323,18 @@ impl ApplicationHandler for App<'_> {
                     .unwrap(),
             );
             self.window = Some(window.clone());
+            self.scale_factor = window.scale_factor();
+
+            // Drop the previous frame timestamp so the first frame after a resume/recreate does
+            // not report the whole backgrounded wall-clock time as its delta. `first_frame_at`
+            // is kept so `elapsed_seconds` stays monotonic across suspend/resume.
+            self.previous_frame_at = None;
 
             self.handler.window_created(window);
             info!("created the window");
         }
+
+        self.handler.resumed();
     }
 
     fn device_event(&mut self, _: &ActiveEventLoop, _: DeviceId, event: DeviceEvent) {
@@ -223,34 +357,55 @@ impl ApplicationHandler for App<'_> {
     }
 
     fn window_event(&mut self, event_loop: &ActiveEventLoop, id: WindowId, event: WindowEvent) {
-        if id != self.window.as_ref().unwrap().id() {
+        // The window is dropped while suspended (see `suspended`), so events can still
+        // arrive with no live window — bail out instead of unwrapping a `None`.
+        let Some(window) = self.window.clone() else {
+            return;
+        };
+        if id != window.id() {
             return;
         }
 
         match event {
-            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::CloseRequested => {
+                if self.handler.close_requested() {
+                    event_loop.exit();
+                }
+            }
             WindowEvent::Resized(physical_size) => {
                 self.handler.resized(physical_size);
 
                 // This tells winit that we want another frame after this one
-                self.window.as_ref().unwrap().request_redraw();
+                window.request_redraw();
             }
             WindowEvent::RedrawRequested => {
                 // This tells winit that we want another frame after this one
-                self.window.as_ref().unwrap().request_redraw();
+                window.request_redraw();
 
-                let window = self.window.as_mut().unwrap();
                 let cursor_visible_request = self.handler.cursor_should_be_visible();
                 if cursor_visible_request != self.cursor_is_visible {
                     window.set_cursor_visible(cursor_visible_request);
                     self.cursor_is_visible = cursor_visible_request;
                 }
 
-                if self.window.is_some() {
-                    let wants_to_keep_going = self.handler.redraw();
-                    if !wants_to_keep_going {
-                        event_loop.exit();
-                    }
+                let cursor_icon_request = self.handler.cursor_icon();
+                if cursor_icon_request != self.cursor_icon {
+                    window.set_cursor(cursor_icon_request);
+                    self.cursor_icon = cursor_icon_request;
+                }
+
+                let now = Instant::now();
+                let first_frame_at = *self.first_frame_at.get_or_insert(now);
+                let delta_seconds = match self.previous_frame_at {
+                    Some(previous) => now.duration_since(previous).as_secs_f32(),
+                    None => 0.0,
+                };
+                let elapsed_seconds = now.duration_since(first_frame_at).as_secs_f64();
+                self.previous_frame_at = Some(now);
+
+                let wants_to_keep_going = self.handler.redraw(delta_seconds, elapsed_seconds);
+                if !wants_to_keep_going {
+                    event_loop.exit();
                 }
             }
             WindowEvent::Focused(is_focus) => {
@@ -263,13 +418,20 @@ impl ApplicationHandler for App<'_> {
                 }
             }
             WindowEvent::KeyboardInput { event, .. } => {
+                if event.state == ElementState::Pressed {
+                    if let Some(text) = &event.text {
+                        self.handler.text_input(text);
+                    }
+                }
                 self.handler.keyboard_input(event.state, event.physical_key)
             }
 
             WindowEvent::CursorMoved { position, .. } => {
                 if self.cursor_is_visible {
                     // Position makes no sense to user if the cursor is not visible
-                    self.handler.cursor_moved(position)
+                    self.handler.cursor_moved(position);
+                    self.handler
+                        .cursor_moved_logical(position.to_logical(self.scale_factor));
                 }
             }
 
@@ -283,7 +445,11 @@ impl ApplicationHandler for App<'_> {
                 self.handler.mouse_input(state, button)
             }
 
-            WindowEvent::Touch(touch_data) => self.handler.touch(touch_data),
+            WindowEvent::Touch(touch_data) => {
+                self.handler.touch(touch_data);
+                self.handler
+                    .touch_logical(touch_data.location.to_logical(self.scale_factor));
+            }
 
             WindowEvent::ScaleFactorChanged {
                 scale_factor,
@@ -293,6 +459,7 @@ impl ApplicationHandler for App<'_> {
             // Changing the display’s scale factor (e.g. in Control Panel on Windows).
             // Moving the window to a display with a different scale factor.
             {
+                self.scale_factor = scale_factor;
                 self.handler
                     .scale_factor_changed(scale_factor, inner_size_writer)
             }
@@ -314,15 +481,26 @@ impl ApplicationHandler for App<'_> {
             // WindowEvent::ActivationTokenDone { .. } => {} winit handles this normally, so no need to implement it.
             // WindowEvent::Moved(_) => {} // since this is not supported on all platforms, it should not be exposed in this library
             // WindowEvent::Destroyed => {} // this is handled internally
-            // since this crate is mostly for games, this file operations are outside the scope.
-            //WindowEvent::DroppedFile(_) => {}
-            //WindowEvent::HoveredFile(_) => {}
-            //WindowEvent::HoveredFileCancelled => {}
+            WindowEvent::DroppedFile(path) => self.handler.file_dropped(path),
+
+            WindowEvent::HoveredFile(path) => self.handler.file_hovered(path),
+
+            WindowEvent::HoveredFileCancelled => self.handler.file_hover_cancelled(),
+
             _ => {}
         }
     }
 
-    fn suspended(&mut self, _: &ActiveEventLoop) {}
+    fn user_event(&mut self, _: &ActiveEventLoop, event: T) {
+        self.handler.user_event(event);
+    }
+
+    fn suspended(&mut self, _: &ActiveEventLoop) {
+        // Drop the window so the surface is rebuilt (and `window_created` fires again)
+        // the next time `resumed` is called. On Android the surface is gone by now anyway.
+        self.window = None;
+        self.handler.suspended();
+    }
 
     fn exiting(&mut self, _: &ActiveEventLoop) {}
 }
@@ -356,9 +534,13 @@ impl WindowRunner {
     ///
     /// It is not guaranteed to ever return, as the event loop will run indefinitely
     /// until the application is terminated.
-    pub fn run_app(handler: &mut dyn AppHandler, title: &str) -> Result<(), EventLoopError> {
-        let event_loop = EventLoop::new()?;
+    pub fn run_app<T: 'static>(
+        handler: &mut dyn AppHandler<UserEvent = T>,
+        title: &str,
+    ) -> Result<(), EventLoopError> {
+        let event_loop = EventLoop::<T>::with_user_event().build()?;
         event_loop.set_control_flow(ControlFlow::Poll);
+        handler.proxy_ready(event_loop.create_proxy());
         let min_size = handler.min_size();
         let start_size = handler.start_size();
         let mut app = App::new(handler, title, min_size, start_size);